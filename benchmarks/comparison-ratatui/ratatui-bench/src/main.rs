@@ -1,3 +1,9 @@
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{self, Event, KeyCode},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
 use ratatui::{
     backend::CrosstermBackend,
     layout::Rect,
@@ -6,16 +12,112 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame, Terminal,
 };
+use serde::Deserialize;
 use std::{
     env,
+    io,
     time::{Duration, Instant},
 };
 
-const ITEM_COUNT: usize = 100;
+const DASHBOARD_HISTORY: usize = 32;
+const SPARKLINE_GLYPHS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
-fn render_file_tree(frame: &mut Frame, selected_index: usize) {
+fn default_item_count() -> usize {
+    100
+}
+fn default_grid_rows() -> usize {
+    50
+}
+fn default_grid_cols() -> usize {
+    200
+}
+fn default_viewport_width() -> u16 {
+    60
+}
+fn default_viewport_height() -> u16 {
+    40
+}
+fn default_update_count() -> usize {
+    1000
+}
+fn default_fps_duration_secs() -> u64 {
+    1
+}
+fn default_idle_duration_secs() -> u64 {
+    2
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct BenchConfig {
+    item_count: usize,
+    grid_rows: usize,
+    grid_cols: usize,
+    viewport_width: u16,
+    viewport_height: u16,
+    update_count: usize,
+    fps_duration_secs: u64,
+    idle_duration_secs: u64,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            item_count: default_item_count(),
+            grid_rows: default_grid_rows(),
+            grid_cols: default_grid_cols(),
+            viewport_width: default_viewport_width(),
+            viewport_height: default_viewport_height(),
+            update_count: default_update_count(),
+            fps_duration_secs: default_fps_duration_secs(),
+            idle_duration_secs: default_idle_duration_secs(),
+        }
+    }
+}
+
+impl BenchConfig {
+    fn load(path: Option<&str>) -> Self {
+        let Some(path) = path else {
+            return Self::default();
+        };
+
+        let config = match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).unwrap_or_else(|err| {
+                eprintln!("Failed to parse config at {path}: {err}, using defaults");
+                Self::default()
+            }),
+            Err(err) => {
+                eprintln!("Failed to read config at {path}: {err}, using defaults");
+                Self::default()
+            }
+        };
+
+        config.validated()
+    }
+
+    /// Floors every field that the benchmarks divide or index by at 1, so a
+    /// typo'd or adversarial TOML file (e.g. `item_count = 0`) can't panic
+    /// the measurement loops with a divide-by-zero.
+    fn validated(self) -> Self {
+        Self {
+            item_count: self.item_count.max(1),
+            grid_rows: self.grid_rows.max(1),
+            grid_cols: self.grid_cols.max(1),
+            viewport_width: self.viewport_width.max(1),
+            viewport_height: self.viewport_height.max(1),
+            update_count: self.update_count.max(1),
+            fps_duration_secs: self.fps_duration_secs.max(1),
+            idle_duration_secs: self.idle_duration_secs.max(1),
+        }
+    }
+}
+
+fn render_file_tree(frame: &mut Frame, selected_index: usize, item_count: usize) {
     let area = frame.size();
+    render_file_tree_in(frame, selected_index, item_count, area);
+}
 
+fn render_file_tree_in(frame: &mut Frame, selected_index: usize, item_count: usize, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
@@ -25,10 +127,10 @@ fn render_file_tree(frame: &mut Frame, selected_index: usize) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let mut lines: Vec<Line> = Vec::with_capacity(ITEM_COUNT + 2);
+    let mut lines: Vec<Line> = Vec::with_capacity(item_count + 2);
     lines.push(Line::from(""));
 
-    for i in 0..ITEM_COUNT {
+    for i in 0..item_count {
         let is_selected = i == selected_index;
         let prefix = if is_selected { "> " } else { "  " };
         let text = format!("{}├── file-{:03}.go", prefix, i);
@@ -75,44 +177,62 @@ fn render_large_grid(frame: &mut Frame, rows: usize, cols: usize, highlight: usi
     frame.render_widget(paragraph, area);
 }
 
-fn measure_startup() {
-    let start = Instant::now();
+fn measure_startup(config: &BenchConfig, samples: usize, warmup: usize, verbose: bool) -> f64 {
+    let median_ms = repeat_with_warmup(samples, warmup, || {
+        let start = Instant::now();
 
-    // Create a dummy buffer backend to avoid terminal manipulation
-    let mut buffer = Vec::new();
-    let backend = CrosstermBackend::new(&mut buffer);
-    let mut terminal = Terminal::new(backend).unwrap();
+        // Create a dummy buffer backend to avoid terminal manipulation
+        let mut buffer = Vec::new();
+        let backend = CrosstermBackend::new(&mut buffer);
+        let mut terminal = Terminal::new(backend).unwrap();
 
-    terminal
-        .draw(|frame| {
-            render_file_tree(frame, 0);
-        })
-        .unwrap();
+        terminal
+            .draw(|frame| {
+                render_file_tree(frame, 0, config.item_count);
+            })
+            .unwrap();
 
-    let elapsed = start.elapsed();
-    println!("Startup time: {:.2}ms", elapsed.as_secs_f64() * 1000.0);
+        start.elapsed().as_secs_f64() * 1000.0
+    });
+
+    if verbose {
+        println!(
+            "Startup time: {:.2}ms (median of {} samples, {} warmup)",
+            median_ms, samples, warmup
+        );
+    }
+    median_ms
 }
 
-fn measure_memory() {
-    // Get memory before
-    let before = get_memory_usage();
+fn measure_memory(config: &BenchConfig, samples: usize, warmup: usize, verbose: bool) -> f64 {
+    let median_mb = repeat_with_warmup(samples, warmup, || {
+        // Get memory before
+        let before = get_memory_usage();
 
-    // Create terminal and render
-    let mut buffer = Vec::new();
-    let backend = CrosstermBackend::new(&mut buffer);
-    let mut terminal = Terminal::new(backend).unwrap();
+        // Create terminal and render
+        let mut buffer = Vec::new();
+        let backend = CrosstermBackend::new(&mut buffer);
+        let mut terminal = Terminal::new(backend).unwrap();
 
-    terminal
-        .draw(|frame| {
-            render_file_tree(frame, 0);
-        })
-        .unwrap();
+        terminal
+            .draw(|frame| {
+                render_file_tree(frame, 0, config.item_count);
+            })
+            .unwrap();
+
+        // Get memory after
+        let after = get_memory_usage();
 
-    // Get memory after
-    let after = get_memory_usage();
+        (after.saturating_sub(before)) as f64 / (1024.0 * 1024.0)
+    });
 
-    let used_mb = (after.saturating_sub(before)) as f64 / (1024.0 * 1024.0);
-    println!("Memory used: {:.2} MB", used_mb);
+    if verbose {
+        println!(
+            "Memory used: {:.2} MB (median of {} samples, {} warmup)",
+            median_mb, samples, warmup
+        );
+    }
+    median_mb
 }
 
 #[cfg(target_os = "macos")]
@@ -177,7 +297,49 @@ fn get_memory_usage() -> usize {
     0
 }
 
-#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+#[cfg(windows)]
+fn get_memory_usage() -> usize {
+    use std::mem::size_of;
+
+    #[repr(C)]
+    struct ProcessMemoryCounters {
+        cb: u32,
+        page_fault_count: u32,
+        peak_working_set_size: usize,
+        working_set_size: usize,
+        quota_peak_paged_pool_usage: usize,
+        quota_paged_pool_usage: usize,
+        quota_peak_non_paged_pool_usage: usize,
+        quota_non_paged_pool_usage: usize,
+        pagefile_usage: usize,
+        peak_pagefile_usage: usize,
+    }
+
+    // K32GetProcessMemoryInfo is exported directly by kernel32.dll (unlike
+    // GetProcessMemoryInfo, which lives in psapi.dll and would need an extra
+    // #[link(name = "psapi")]), so it needs no additional linker flags.
+    #[allow(non_snake_case)]
+    extern "system" {
+        fn GetCurrentProcess() -> isize;
+        fn K32GetProcessMemoryInfo(
+            process: isize,
+            counters: *mut ProcessMemoryCounters,
+            cb: u32,
+        ) -> i32;
+    }
+
+    unsafe {
+        let mut counters: ProcessMemoryCounters = std::mem::zeroed();
+        let cb = size_of::<ProcessMemoryCounters>() as u32;
+        if K32GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, cb) != 0 {
+            counters.working_set_size
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", windows)))]
 fn get_memory_usage() -> usize {
     0
 }
@@ -224,169 +386,534 @@ fn get_cpu_time() -> Duration {
     }
 }
 
-#[cfg(not(unix))]
+#[cfg(windows)]
+fn get_cpu_time() -> Duration {
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct FileTime {
+        low_date_time: u32,
+        high_date_time: u32,
+    }
+
+    #[allow(non_snake_case)]
+    extern "system" {
+        fn GetCurrentProcess() -> isize;
+        fn GetProcessTimes(
+            process: isize,
+            creation_time: *mut FileTime,
+            exit_time: *mut FileTime,
+            kernel_time: *mut FileTime,
+            user_time: *mut FileTime,
+        ) -> i32;
+    }
+
+    fn filetime_to_duration(ft: FileTime) -> Duration {
+        let ticks = ((ft.high_date_time as u64) << 32) | ft.low_date_time as u64;
+        let nanos_100 = ticks * 100;
+        Duration::new(nanos_100 / 1_000_000_000, (nanos_100 % 1_000_000_000) as u32)
+    }
+
+    unsafe {
+        let mut creation_time = FileTime { low_date_time: 0, high_date_time: 0 };
+        let mut exit_time = FileTime { low_date_time: 0, high_date_time: 0 };
+        let mut kernel_time = FileTime { low_date_time: 0, high_date_time: 0 };
+        let mut user_time = FileTime { low_date_time: 0, high_date_time: 0 };
+
+        if GetProcessTimes(
+            GetCurrentProcess(),
+            &mut creation_time,
+            &mut exit_time,
+            &mut kernel_time,
+            &mut user_time,
+        ) != 0
+        {
+            filetime_to_duration(kernel_time) + filetime_to_duration(user_time)
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
 fn get_cpu_time() -> Duration {
     Duration::ZERO
 }
 
-fn measure_idle_cpu() {
-    // Create terminal
-    let mut buffer = Vec::new();
-    let backend = CrosstermBackend::new(&mut buffer);
-    let mut terminal = Terminal::new(backend).unwrap();
+struct FrameStats {
+    min: Duration,
+    max: Duration,
+    mean: Duration,
+    stddev: Duration,
+    p50: Duration,
+    p95: Duration,
+    p99: Duration,
+}
 
-    // Initial render
-    terminal
-        .draw(|frame| {
-            render_file_tree(frame, 0);
-        })
-        .unwrap();
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let n = sorted.len();
+    let index = ((p * n as f64).ceil() as usize).saturating_sub(1).min(n - 1);
+    sorted[index]
+}
 
-    // Measure CPU over 2 seconds idle
-    let cpu_start = get_cpu_time();
-    let start = Instant::now();
+fn compute_frame_stats(samples: &[Duration]) -> Option<FrameStats> {
+    if samples.is_empty() {
+        return None;
+    }
 
-    std::thread::sleep(Duration::from_secs(2));
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
 
-    let cpu_end = get_cpu_time();
-    let elapsed = start.elapsed();
+    let n = sorted.len() as f64;
+    let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+    let variance = sorted
+        .iter()
+        .map(|d| {
+            let diff = d.as_secs_f64() - mean_secs;
+            diff * diff
+        })
+        .sum::<f64>()
+        / n;
 
-    let cpu_used = cpu_end.saturating_sub(cpu_start);
-    let cpu_percent = (cpu_used.as_secs_f64() / elapsed.as_secs_f64()) * 100.0;
+    Some(FrameStats {
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        mean: Duration::from_secs_f64(mean_secs),
+        stddev: Duration::from_secs_f64(variance.sqrt()),
+        p50: percentile(&sorted, 0.50),
+        p95: percentile(&sorted, 0.95),
+        p99: percentile(&sorted, 0.99),
+    })
+}
 
-    println!("Idle CPU: {:.2}%", cpu_percent);
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = values.len();
+    if n % 2 == 1 {
+        values[n / 2]
+    } else {
+        (values[n / 2 - 1] + values[n / 2]) / 2.0
+    }
 }
 
-fn measure_updates() {
-    let mut buffer = Vec::new();
-    let backend = CrosstermBackend::new(&mut buffer);
-    // Use fixed viewport to match goli benchmark (60x40)
-    let mut terminal = Terminal::with_options(
-        backend,
-        ratatui::TerminalOptions {
-            viewport: ratatui::Viewport::Fixed(Rect::new(0, 0, 60, 40)),
-        },
-    ).unwrap();
+fn repeat_with_warmup(samples: usize, warmup: usize, mut run: impl FnMut() -> f64) -> f64 {
+    let mut results: Vec<f64> = (0..samples + warmup).map(|_| run()).collect();
+    let kept = &mut results[warmup..];
+    median(kept)
+}
 
-    // Measure 1000 updates
-    let start = Instant::now();
-    for i in 0..1000 {
-        let selected = i % ITEM_COUNT;
-        terminal
-            .draw(|frame| {
-                render_file_tree(frame, selected);
-            })
-            .unwrap();
-    }
-    let elapsed = start.elapsed();
+fn print_frame_stats(label: &str, stats: Option<&FrameStats>) {
+    let Some(stats) = stats else {
+        println!("{label}: no samples collected");
+        return;
+    };
 
-    let updates_per_sec = 1000.0 / elapsed.as_secs_f64();
     println!(
-        "1000 updates: {:.0}ms ({:.0} updates/sec)",
-        elapsed.as_secs_f64() * 1000.0,
-        updates_per_sec
+        "{label}: min={:.2}ms mean={:.2}ms stddev={:.2}ms p50={:.2}ms p95={:.2}ms p99={:.2}ms max={:.2}ms",
+        stats.min.as_secs_f64() * 1000.0,
+        stats.mean.as_secs_f64() * 1000.0,
+        stats.stddev.as_secs_f64() * 1000.0,
+        stats.p50.as_secs_f64() * 1000.0,
+        stats.p95.as_secs_f64() * 1000.0,
+        stats.p99.as_secs_f64() * 1000.0,
+        stats.max.as_secs_f64() * 1000.0,
     );
 }
 
-fn measure_fps() {
-    let mut buffer = Vec::new();
-    let backend = CrosstermBackend::new(&mut buffer);
-    // Use fixed viewport to match goli benchmark (60x40)
-    let mut terminal = Terminal::with_options(
-        backend,
-        ratatui::TerminalOptions {
-            viewport: ratatui::Viewport::Fixed(Rect::new(0, 0, 60, 40)),
-        },
-    ).unwrap();
-
-    // Measure frames over 1 second
-    let mut render_count = 0;
-    let mut selected = 0;
-    let start = Instant::now();
-    let deadline = start + Duration::from_secs(1);
+fn measure_idle_cpu(config: &BenchConfig, samples: usize, warmup: usize, verbose: bool) -> f64 {
+    let median_percent = repeat_with_warmup(samples, warmup, || {
+        // Create terminal
+        let mut buffer = Vec::new();
+        let backend = CrosstermBackend::new(&mut buffer);
+        let mut terminal = Terminal::new(backend).unwrap();
 
-    while Instant::now() < deadline {
+        // Initial render
         terminal
             .draw(|frame| {
-                render_file_tree(frame, selected);
+                render_file_tree(frame, 0, config.item_count);
             })
             .unwrap();
-        render_count += 1;
-        selected = (selected + 1) % ITEM_COUNT;
+
+        // Measure CPU over the configured idle window
+        let cpu_start = get_cpu_time();
+        let start = Instant::now();
+
+        std::thread::sleep(Duration::from_secs(config.idle_duration_secs));
+
+        let cpu_end = get_cpu_time();
+        let elapsed = start.elapsed();
+
+        let cpu_used = cpu_end.saturating_sub(cpu_start);
+        (cpu_used.as_secs_f64() / elapsed.as_secs_f64()) * 100.0
+    });
+
+    if verbose {
+        println!(
+            "Idle CPU: {:.2}% (median of {} samples, {} warmup)",
+            median_percent, samples, warmup
+        );
     }
+    median_percent
+}
+
+fn measure_updates(config: &BenchConfig, samples: usize, warmup: usize, verbose: bool) -> f64 {
+    let mut last_frame_times = Vec::new();
+    let median_updates_per_sec = repeat_with_warmup(samples, warmup, || {
+        let mut buffer = Vec::new();
+        let backend = CrosstermBackend::new(&mut buffer);
+        let mut terminal = Terminal::with_options(
+            backend,
+            ratatui::TerminalOptions {
+                viewport: ratatui::Viewport::Fixed(Rect::new(
+                    0,
+                    0,
+                    config.viewport_width,
+                    config.viewport_height,
+                )),
+            },
+        ).unwrap();
+
+        // Measure the configured number of updates
+        let mut frame_times = Vec::with_capacity(config.update_count);
+        let start = Instant::now();
+        for i in 0..config.update_count {
+            let selected = i % config.item_count;
+            let frame_start = Instant::now();
+            terminal
+                .draw(|frame| {
+                    render_file_tree(frame, selected, config.item_count);
+                })
+                .unwrap();
+            frame_times.push(frame_start.elapsed());
+        }
+        let elapsed = start.elapsed();
 
-    let elapsed = start.elapsed();
-    let fps = render_count as f64 / elapsed.as_secs_f64();
+        last_frame_times = frame_times;
+        config.update_count as f64 / elapsed.as_secs_f64()
+    });
 
-    println!("Max FPS: {:.0} (60x40 screen, 100 items)", fps);
+    if verbose {
+        println!(
+            "{} updates: {:.0} updates/sec (median of {} samples, {} warmup)",
+            config.update_count, median_updates_per_sec, samples, warmup
+        );
+        print_frame_stats("  frame time", compute_frame_stats(&last_frame_times).as_ref());
+    }
+    median_updates_per_sec
 }
 
-fn measure_large_screen() {
-    let rows = 50;
-    let cols = 200;
+fn measure_fps(config: &BenchConfig, samples: usize, warmup: usize, verbose: bool) -> f64 {
+    let mut last_frame_times = Vec::new();
+    let median_fps = repeat_with_warmup(samples, warmup, || {
+        let mut buffer = Vec::new();
+        let backend = CrosstermBackend::new(&mut buffer);
+        let mut terminal = Terminal::with_options(
+            backend,
+            ratatui::TerminalOptions {
+                viewport: ratatui::Viewport::Fixed(Rect::new(
+                    0,
+                    0,
+                    config.viewport_width,
+                    config.viewport_height,
+                )),
+            },
+        ).unwrap();
+
+        // Measure frames over the configured window
+        let mut render_count = 0;
+        let mut selected = 0;
+        let mut frame_times = Vec::new();
+        let start = Instant::now();
+        let deadline = start + Duration::from_secs(config.fps_duration_secs);
+
+        while Instant::now() < deadline {
+            let frame_start = Instant::now();
+            terminal
+                .draw(|frame| {
+                    render_file_tree(frame, selected, config.item_count);
+                })
+                .unwrap();
+            frame_times.push(frame_start.elapsed());
+            render_count += 1;
+            selected = (selected + 1) % config.item_count;
+        }
+
+        let elapsed = start.elapsed();
+        last_frame_times = frame_times;
+        render_count as f64 / elapsed.as_secs_f64()
+    });
+
+    if verbose {
+        println!(
+            "Max FPS: {:.0} ({}x{} screen, {} items, median of {} samples, {} warmup)",
+            median_fps, config.viewport_width, config.viewport_height, config.item_count, samples, warmup
+        );
+        print_frame_stats("  frame time", compute_frame_stats(&last_frame_times).as_ref());
+    }
+    median_fps
+}
+
+fn measure_large_screen(config: &BenchConfig, samples: usize, warmup: usize, verbose: bool) -> f64 {
+    let rows = config.grid_rows;
+    let cols = config.grid_cols;
     let total_cells = rows * cols;
+    let mut last_frame_times = Vec::new();
 
-    let mut buffer = Vec::new();
-    let backend = CrosstermBackend::new(&mut buffer);
-    let mut terminal = Terminal::with_options(
-        backend,
-        ratatui::TerminalOptions {
-            viewport: ratatui::Viewport::Fixed(Rect::new(0, 0, cols as u16, rows as u16)),
-        },
-    )
-    .unwrap();
+    let median_fps = repeat_with_warmup(samples, warmup, || {
+        let mut buffer = Vec::new();
+        let backend = CrosstermBackend::new(&mut buffer);
+        let mut terminal = Terminal::with_options(
+            backend,
+            ratatui::TerminalOptions {
+                viewport: ratatui::Viewport::Fixed(Rect::new(0, 0, cols as u16, rows as u16)),
+            },
+        )
+        .unwrap();
 
-    // Measure frames over 1 second
-    let mut render_count = 0;
-    let mut highlight = 0;
-    let start = Instant::now();
-    let deadline = start + Duration::from_secs(1);
+        // Measure frames over the configured window
+        let mut render_count = 0;
+        let mut highlight = 0;
+        let mut frame_times = Vec::new();
+        let start = Instant::now();
+        let deadline = start + Duration::from_secs(config.fps_duration_secs);
 
-    while Instant::now() < deadline {
-        terminal
-            .draw(|frame| {
-                render_large_grid(frame, rows, cols, highlight);
-            })
-            .unwrap();
-        render_count += 1;
-        highlight = (highlight + 1) % total_cells;
+        while Instant::now() < deadline {
+            let frame_start = Instant::now();
+            terminal
+                .draw(|frame| {
+                    render_large_grid(frame, rows, cols, highlight);
+                })
+                .unwrap();
+            frame_times.push(frame_start.elapsed());
+            render_count += 1;
+            highlight = (highlight + 1) % total_cells;
+        }
+
+        let elapsed = start.elapsed();
+        last_frame_times = frame_times;
+        render_count as f64 / elapsed.as_secs_f64()
+    });
+
+    if verbose {
+        println!(
+            "Large screen FPS: {:.0} ({}x{} = {} cells, median of {} samples, {} warmup)",
+            median_fps, cols, rows, total_cells, samples, warmup
+        );
+        print_frame_stats("  frame time", compute_frame_stats(&last_frame_times).as_ref());
     }
+    median_fps
+}
 
-    let elapsed = start.elapsed();
-    let fps = render_count as f64 / elapsed.as_secs_f64();
+#[derive(Debug, serde::Serialize)]
+struct BenchResults {
+    startup_ms: f64,
+    memory_mb: f64,
+    idle_cpu_percent: f64,
+    updates_per_sec: f64,
+    fps: f64,
+    large_screen_fps: f64,
+}
 
-    println!(
-        "Large screen FPS: {:.0} ({}x{} = {} cells)",
-        fps, cols, rows, total_cells
-    );
+fn run_all_benchmarks(config: &BenchConfig, samples: usize, warmup: usize, json: bool) {
+    if !json {
+        println!("=== ratatui Benchmark ===");
+        println!(
+            "Rust version: {}\n",
+            env!("CARGO_PKG_RUST_VERSION")
+                .is_empty()
+                .then_some("stable")
+                .unwrap_or(env!("CARGO_PKG_RUST_VERSION"))
+        );
+    }
+
+    let verbose = !json;
+    let startup_ms = measure_startup(config, samples, warmup, verbose);
+    let memory_mb = measure_memory(config, samples, warmup, verbose);
+    let idle_cpu_percent = measure_idle_cpu(config, samples, warmup, verbose);
+    let updates_per_sec = measure_updates(config, samples, warmup, verbose);
+    let fps = measure_fps(config, samples, warmup, verbose);
+    let large_screen_fps = measure_large_screen(config, samples, warmup, verbose);
+
+    if json {
+        let results = BenchResults {
+            startup_ms,
+            memory_mb,
+            idle_cpu_percent,
+            updates_per_sec,
+            fps,
+            large_screen_fps,
+        };
+        println!("{}", serde_json::to_string(&results).unwrap());
+    }
 }
 
-fn run_all_benchmarks() {
-    println!("=== ratatui Benchmark ===");
-    println!("Rust version: {}\n", env!("CARGO_PKG_RUST_VERSION").is_empty().then(|| "stable").unwrap_or(env!("CARGO_PKG_RUST_VERSION")));
+struct FrameHistory {
+    samples: [Duration; DASHBOARD_HISTORY],
+    write_index: usize,
+    filled: bool,
+}
 
-    measure_startup();
-    measure_memory();
-    measure_idle_cpu();
-    measure_updates();
-    measure_fps();
-    measure_large_screen();
+impl FrameHistory {
+    fn new() -> Self {
+        Self {
+            samples: [Duration::ZERO; DASHBOARD_HISTORY],
+            write_index: 0,
+            filled: false,
+        }
+    }
+
+    fn push(&mut self, sample: Duration) {
+        self.samples[self.write_index] = sample;
+        self.write_index = (self.write_index + 1) % DASHBOARD_HISTORY;
+        if self.write_index == 0 {
+            self.filled = true;
+        }
+    }
+
+    fn window(&self) -> &[Duration] {
+        if self.filled {
+            &self.samples
+        } else {
+            &self.samples[..self.write_index]
+        }
+    }
+
+    fn mean(&self) -> Duration {
+        let window = self.window();
+        if window.is_empty() {
+            return Duration::ZERO;
+        }
+        let total: Duration = window.iter().sum();
+        total / window.len() as u32
+    }
+}
+
+fn sparkline_spans(history: &FrameHistory) -> Vec<Span<'static>> {
+    let window = history.window();
+    if window.is_empty() {
+        return Vec::new();
+    }
+
+    let min = window.iter().min().copied().unwrap_or(Duration::ZERO);
+    let max = window.iter().max().copied().unwrap_or(Duration::ZERO);
+    let range = (max.as_secs_f64() - min.as_secs_f64()).max(f64::EPSILON);
+
+    window
+        .iter()
+        .map(|sample| {
+            let ratio = (sample.as_secs_f64() - min.as_secs_f64()) / range;
+            let glyph_index = (ratio * (SPARKLINE_GLYPHS.len() - 1) as f64).round() as usize;
+            let style = if glyph_index >= SPARKLINE_GLYPHS.len() - 3 {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            Span::styled(SPARKLINE_GLYPHS[glyph_index].to_string(), style)
+        })
+        .collect()
+}
+
+fn run_dashboard_loop(config: &BenchConfig) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    let backend = CrosstermBackend::new(&mut stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut history = FrameHistory::new();
+    let mut selected = 0;
+
+    loop {
+        if event::poll(Duration::from_millis(0))? {
+            if let Event::Key(key) = event::read()? {
+                if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                    break;
+                }
+            }
+        }
+
+        let mean = history.mean();
+        let fps = if mean.as_secs_f64() > 0.0 {
+            1.0 / mean.as_secs_f64()
+        } else {
+            0.0
+        };
+        let mut spans = vec![Span::raw(format!("{:>6.1} fps  ", fps))];
+        spans.extend(sparkline_spans(&history));
+
+        let frame_start = Instant::now();
+        terminal.draw(|frame| {
+            let area = frame.size();
+            let sparkline_area = Rect::new(area.x, area.y, area.width, 1);
+            let body_area = Rect::new(area.x, area.y + 1, area.width, area.height.saturating_sub(1));
+
+            frame.render_widget(Paragraph::new(Line::from(spans)), sparkline_area);
+            render_file_tree_in(frame, selected, config.item_count, body_area);
+        })?;
+        history.push(frame_start.elapsed());
+        selected = (selected + 1) % config.item_count;
+    }
+
+    Ok(())
+}
+
+fn run_dashboard(config: &BenchConfig) -> io::Result<()> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+    let result = run_dashboard_loop(config);
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+    let _ = disable_raw_mode();
+    result
+}
+
+const DEFAULT_SAMPLES: usize = 5;
+const DEFAULT_WARMUP: usize = 1;
+
+fn parse_usize_flag(args: &[String], flag: &str, default: usize) -> usize {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(default)
 }
 
 fn main() {
     let args: Vec<String> = env::args().collect();
     let mode = args.get(1).map(|s| s.as_str()).unwrap_or("benchmark");
 
+    let config_path = args
+        .iter()
+        .position(|a| a == "--config")
+        .and_then(|i| args.get(i + 1))
+        .map(|s| s.as_str());
+    let json = args.iter().any(|a| a == "--json");
+    let samples = parse_usize_flag(&args, "--samples", DEFAULT_SAMPLES).max(1);
+    let warmup = parse_usize_flag(&args, "--warmup", DEFAULT_WARMUP);
+
+    let config = BenchConfig::load(config_path);
+
     match mode {
-        "startup" => measure_startup(),
-        "memory" => measure_memory(),
-        "idle" => measure_idle_cpu(),
-        "updates" => measure_updates(),
-        "fps" => measure_fps(),
-        "large" => measure_large_screen(),
-        "benchmark" => run_all_benchmarks(),
+        "startup" => {
+            measure_startup(&config, samples, warmup, true);
+        }
+        "memory" => {
+            measure_memory(&config, samples, warmup, true);
+        }
+        "idle" => {
+            measure_idle_cpu(&config, samples, warmup, true);
+        }
+        "updates" => {
+            measure_updates(&config, samples, warmup, true);
+        }
+        "fps" => {
+            measure_fps(&config, samples, warmup, true);
+        }
+        "large" => {
+            measure_large_screen(&config, samples, warmup, true);
+        }
+        "benchmark" => run_all_benchmarks(&config, samples, warmup, json),
+        "dashboard" => run_dashboard(&config).unwrap(),
         "debug" => debug_sizes(),
-        _ => println!("Usage: ratatui-bench [startup|memory|idle|updates|fps|large|benchmark|debug]"),
+        _ => println!(
+            "Usage: ratatui-bench [startup|memory|idle|updates|fps|large|benchmark|dashboard|debug] [--config <path>] [--json] [--samples <n>] [--warmup <n>]"
+        ),
     }
 }
 
@@ -414,3 +941,97 @@ fn debug_sizes() {
         println!("Fixed viewport frame.size(): {:?}", frame.size());
     }).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_frame_stats_empty_returns_none() {
+        assert!(compute_frame_stats(&[]).is_none());
+    }
+
+    #[test]
+    fn compute_frame_stats_single_sample() {
+        let stats = compute_frame_stats(&[Duration::from_millis(10)]).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(stats.max, Duration::from_millis(10));
+        assert_eq!(stats.mean, Duration::from_millis(10));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.p50, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn compute_frame_stats_min_max_and_percentiles() {
+        let samples: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+        let stats = compute_frame_stats(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(10));
+        assert_eq!(stats.p50, Duration::from_millis(5));
+        assert_eq!(stats.p95, Duration::from_millis(10));
+        assert_eq!(stats.p99, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn percentile_clamps_to_last_index() {
+        let sorted = vec![Duration::from_millis(1), Duration::from_millis(2)];
+        assert_eq!(percentile(&sorted, 0.99), Duration::from_millis(2));
+        assert_eq!(percentile(&sorted, 0.0), Duration::from_millis(1));
+    }
+
+    #[test]
+    fn median_odd_length_returns_middle_value() {
+        let mut values = vec![3.0, 1.0, 2.0];
+        assert_eq!(median(&mut values), 2.0);
+    }
+
+    #[test]
+    fn median_even_length_averages_middle_pair() {
+        let mut values = vec![4.0, 1.0, 3.0, 2.0];
+        assert_eq!(median(&mut values), 2.5);
+    }
+
+    #[test]
+    fn repeat_with_warmup_discards_warmup_results() {
+        let mut call = 0;
+        let result = repeat_with_warmup(3, 2, || {
+            call += 1;
+            // Warmup calls return huge outliers; only the 3 kept calls (3.0, 4.0, 5.0) should
+            // factor into the median if warmup is discarded correctly.
+            if call <= 2 { 1000.0 } else { call as f64 }
+        });
+        assert_eq!(result, 4.0);
+    }
+
+    #[test]
+    fn frame_history_window_grows_until_filled() {
+        let mut history = FrameHistory::new();
+        assert!(history.window().is_empty());
+        history.push(Duration::from_millis(1));
+        history.push(Duration::from_millis(2));
+        assert_eq!(history.window().len(), 2);
+        assert!(!history.filled);
+    }
+
+    #[test]
+    fn frame_history_wraps_around_and_overwrites_oldest() {
+        let mut history = FrameHistory::new();
+        for i in 0..DASHBOARD_HISTORY {
+            history.push(Duration::from_millis(i as u64));
+        }
+        assert!(history.filled);
+        assert_eq!(history.window().len(), DASHBOARD_HISTORY);
+
+        // One more push should overwrite the oldest sample (millis(0)) with millis(100).
+        history.push(Duration::from_millis(100));
+        assert_eq!(history.window().len(), DASHBOARD_HISTORY);
+        assert!(history.window().contains(&Duration::from_millis(100)));
+        assert!(!history.window().contains(&Duration::from_millis(0)));
+    }
+
+    #[test]
+    fn frame_history_mean_of_empty_is_zero() {
+        let history = FrameHistory::new();
+        assert_eq!(history.mean(), Duration::ZERO);
+    }
+}